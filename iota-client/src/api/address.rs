@@ -1,42 +1,57 @@
 // Copyright 2020 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{Client, Error, Result};
-
-use bee_message::prelude::{Address, Ed25519Address};
-use bee_signing_ext::{
-    binary::{BIP32Path, Ed25519PrivateKey, Ed25519Seed},
-    Seed,
-};
-use blake2::{
-    digest::{Update, VariableOutput},
-    VarBlake2b,
+use crate::{
+    signing::{signature_scheme_for_seed, SignatureScheme},
+    Client, Result,
 };
-use core::convert::TryInto;
+
+use bee_message::prelude::Address;
+use bee_signing_ext::{binary::BIP32Path, Seed};
 use std::ops::Range;
 
-const HARDEND: u32 = 1 << 31;
+/// Default gap limit, i.e. the number of consecutive unused addresses (per chain) that need to
+/// be seen before a scan is considered finished, as specified by BIP44.
+const DEFAULT_GAP_LIMIT: usize = 20;
+
+/// An address discovered while scanning, together with what the node reported about it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressWithBalance {
+    /// The address itself.
+    pub address: Address,
+    /// Whether this address belongs to the internal (change) chain.
+    pub internal: bool,
+    /// BIP44 address index.
+    pub index: usize,
+    /// Balance of the address, as reported by the node.
+    pub balance: u64,
+    /// Whether the address has ever been used (has a balance or past outputs).
+    pub used: bool,
+}
 
 /// Builder of find_addresses API
 pub struct GetAddressesBuilder<'a> {
-    _client: &'a Client,
+    client: &'a Client,
     seed: &'a Seed,
     account_index: Option<usize>,
     range: Option<Range<usize>>,
+    gap_limit: usize,
 }
 
 impl<'a> GetAddressesBuilder<'a> {
     /// Create find_addresses builder
-    pub fn new(_client: &'a Client, seed: &'a Seed) -> Self {
+    pub fn new(client: &'a Client, seed: &'a Seed) -> Self {
         Self {
-            _client,
+            client,
             seed,
             account_index: None,
             range: None,
+            gap_limit: DEFAULT_GAP_LIMIT,
         }
     }
 
-    /// Sets the account index.
+    /// Sets the account index. Defaults to the active network spec's
+    /// `default_account_range.start` if not set.
     pub fn account_index(mut self, account_index: usize) -> Self {
         self.account_index = Some(account_index);
         self
@@ -48,53 +63,108 @@ impl<'a> GetAddressesBuilder<'a> {
         self
     }
 
+    /// Sets the gap limit used by [`get_used()`](GetAddressesBuilder::get_used), i.e. the number
+    /// of consecutive unused addresses per chain that have to be seen before scanning stops.
+    pub fn gap_limit(mut self, gap_limit: usize) -> Self {
+        self.gap_limit = gap_limit;
+        self
+    }
+
     /// Consume the builder and get the vector of Address
     pub fn get(self) -> Result<Vec<(Address, bool)>> {
-        let mut path = self
+        let account_index = self
             .account_index
-            .map(|i| BIP32Path::from_str(&crate::account_path!(i)).expect("invalid account index"))
-            .ok_or_else(|| Error::MissingParameter(String::from("account index")))?;
+            .unwrap_or(self.client.network_spec().default_account_range.start);
+        let mut path =
+            BIP32Path::from_str(&crate::account_path!(account_index)).expect("invalid account index");
 
         let range = match self.range {
             Some(r) => r,
-            None => 0..20,
+            None => self.client.network_spec().default_address_range.clone(),
         };
 
-        let seed = match self.seed {
-            Seed::Ed25519(s) => s,
-            _ => panic!("Other seed scheme isn't supported yet."),
-        };
+        let scheme = signature_scheme_for_seed(self.seed)?;
 
         let mut addresses = Vec::new();
         for i in range {
-            let address = generate_address(&seed, &mut path, i, false);
-            let internal_address = generate_address(&seed, &mut path, i, true);
+            let address = scheme.generate_address(&mut path, i, false)?;
+            let internal_address = scheme.generate_address(&mut path, i, true)?;
             addresses.push((address, false));
             addresses.push((internal_address, true));
         }
 
         Ok(addresses)
     }
-}
 
-fn generate_address(seed: &Ed25519Seed, path: &mut BIP32Path, index: usize, internal: bool) -> Address {
-    path.push(internal as u32 + HARDEND);
-    path.push(index as u32 + HARDEND);
-
-    let public_key = Ed25519PrivateKey::generate_from_seed(seed, &path)
-        .expect("Invalid Seed & BIP32Path. Probably because the index of path is not hardened.")
-        .generate_public_key()
-        .to_bytes();
-    // Hash the public key to get the address
-    let mut hasher = VarBlake2b::new(32).unwrap();
-    hasher.update(public_key);
-    let mut result: [u8; 32] = [0; 32];
-    hasher.finalize_variable(|res| {
-        result = res.try_into().expect("Invalid Length of Public Key");
-    });
-
-    path.pop();
-    path.pop();
-
-    Address::Ed25519(Ed25519Address::new(result))
+    /// Consume the builder and scan the external and internal (BIP44) chains for used addresses,
+    /// starting at `range.start` (or the active network spec's `default_address_range.start`),
+    /// and querying the node's balance/output endpoints for each address. Addresses are derived
+    /// and queried one index at a time on both chains, not in batches. Scanning stops once
+    /// `gap_limit` consecutive unused addresses have been seen on *both* chains. Returns every discovered address together with
+    /// its balance/used state, and the index scanning stopped at, so callers can resume a later
+    /// scan from that checkpoint instead of guessing a range.
+    pub async fn get_used(self) -> Result<(Vec<AddressWithBalance>, usize)> {
+        let account_index = self
+            .account_index
+            .unwrap_or(self.client.network_spec().default_account_range.start);
+        let mut path =
+            BIP32Path::from_str(&crate::account_path!(account_index)).expect("invalid account index");
+
+        let scheme = signature_scheme_for_seed(self.seed)?;
+
+        let mut index = self
+            .range
+            .map(|r| r.start)
+            .unwrap_or(self.client.network_spec().default_address_range.start);
+        let gap_limit = self.gap_limit;
+        let mut unused_in_a_row = [0usize; 2];
+        let mut addresses = Vec::new();
+
+        while unused_in_a_row[0] < gap_limit || unused_in_a_row[1] < gap_limit {
+            for (chain, internal) in [(0usize, false), (1usize, true)] {
+                let address = scheme.generate_address(&mut path, index, internal)?;
+                let (balance, used) = self.fetch_balance(&address).await?;
+
+                if used {
+                    unused_in_a_row[chain] = 0;
+                } else {
+                    unused_in_a_row[chain] += 1;
+                }
+
+                addresses.push(AddressWithBalance {
+                    address,
+                    internal,
+                    index,
+                    balance,
+                    used,
+                });
+            }
+            index += 1;
+        }
+
+        Ok((addresses, index))
+    }
+
+    /// Query the node for an address' balance and outputs, and derive its "used" state from them.
+    /// An address counts as used if it ever received funds, even if its current balance is zero.
+    async fn fetch_balance(&self, address: &Address) -> Result<(u64, bool)> {
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let bech32_address = address.to_bech32(&bech32_hrp);
+
+        let balance = self.client.get_address().balance(&bech32_address).await?;
+        // Spent outputs must be included too, otherwise an address that received funds and was
+        // later spent out entirely would come back with balance == 0 and outputs == [], and
+        // would wrongly be reported as unused.
+        let outputs = self
+            .client
+            .get_address()
+            .outputs(
+                &bech32_address,
+                crate::client::OutputsOptions { include_spent: true },
+            )
+            .await?;
+
+        let used = balance > 0 || !outputs.is_empty();
+        Ok((balance, used))
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright 2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Testnet faucet requests for topping up freshly derived addresses.
+
+use crate::{Client, Error, Result};
+
+use bee_message::prelude::Address;
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct FaucetRequest<'a> {
+    address: &'a str,
+}
+
+impl Client {
+    /// Request test funds from the active network spec's faucet for `address`, e.g. one produced
+    /// by [`GetAddressesBuilder`](crate::api::GetAddressesBuilder).
+    ///
+    /// `amount` (in the network's base token unit) is checked against the faucet's per-request
+    /// withdrawal limit locally: a request that would exceed it is rejected with
+    /// [`Error::InvalidParameter`] instead of being silently truncated or bounced by the server.
+    /// Only the address is sent to the faucet; `amount` is not part of the request.
+    pub async fn request_funds(&self, address: &Address, amount: u64) -> Result<String> {
+        let spec = self.network_spec();
+        let faucet_url = spec
+            .faucet_url
+            .as_ref()
+            .ok_or_else(|| Error::MissingParameter(String::from("faucet_url")))?;
+
+        if amount > spec.faucet_max_request {
+            return Err(Error::InvalidParameter(
+                "amount: exceeds the faucet's per-request withdrawal limit",
+            ));
+        }
+
+        let bech32_hrp = self.get_bech32_hrp().await?;
+        let bech32_address = address.to_bech32(&bech32_hrp);
+
+        let response = reqwest::Client::new()
+            .post(faucet_url)
+            .json(&FaucetRequest {
+                address: &bech32_address,
+            })
+            .send()
+            .await?;
+
+        Ok(response.text().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Client, network_spec::NetworkSpec};
+
+    #[tokio::test]
+    async fn request_funds_rejects_amount_over_faucet_limit() {
+        let mut spec = NetworkSpec::testnet();
+        spec.faucet_max_request = 100;
+        let client = Client::builder().with_network_spec(spec).finish().unwrap();
+        let address = Address::Ed25519(bee_message::prelude::Ed25519Address::new([0; 32]));
+
+        let result = client.request_funds(&address, 101).await;
+
+        assert!(matches!(result, Err(Error::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The Client module to connect through HORNET or Bee with API usages
+
+use crate::{
+    error::{Error, Result},
+    network_spec::NetworkSpec,
+};
+
+/// An instance of the client, configured for a particular network and set of nodes.
+pub struct Client {
+    nodes: Vec<String>,
+    network_spec: NetworkSpec,
+}
+
+impl Client {
+    /// Create a [`ClientBuilder`] to configure a new client.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// The network spec this client is configured for.
+    pub fn network_spec(&self) -> &NetworkSpec {
+        &self.network_spec
+    }
+
+    /// Bech32 human readable part used to encode/decode addresses on the configured network.
+    pub async fn get_bech32_hrp(&self) -> Result<String> {
+        Ok(self.network_spec.bech32_hrp.clone())
+    }
+
+    /// Start a request for a single address' balance/outputs.
+    pub fn get_address(&self) -> GetAddressBuilder<'_> {
+        GetAddressBuilder { client: self }
+    }
+
+    fn node(&self) -> Result<&str> {
+        self.nodes
+            .first()
+            .map(String::as_str)
+            .ok_or_else(|| Error::MissingParameter(String::from("node")))
+    }
+}
+
+/// Builder to query a single address' state from the configured node.
+pub struct GetAddressBuilder<'a> {
+    client: &'a Client,
+}
+
+impl<'a> GetAddressBuilder<'a> {
+    /// Get the confirmed balance of `bech32_address`.
+    pub async fn balance(&self, bech32_address: &str) -> Result<u64> {
+        #[derive(serde::Deserialize)]
+        struct BalanceResponse {
+            data: BalanceData,
+        }
+        #[derive(serde::Deserialize)]
+        struct BalanceData {
+            balance: u64,
+        }
+
+        let url = format!("{}/api/v1/addresses/{}", self.client.node()?, bech32_address);
+        let response: BalanceResponse = reqwest::get(&url).await?.json().await?;
+        Ok(response.data.balance)
+    }
+
+    /// Get the output ids of `bech32_address`. The node only returns unspent outputs unless
+    /// `options.include_spent` is set, so a gap-limit scan must pass `include_spent: true` to
+    /// correctly detect an address that was used and later spent out entirely.
+    pub async fn outputs(&self, bech32_address: &str, options: OutputsOptions) -> Result<Vec<String>> {
+        #[derive(serde::Deserialize)]
+        struct OutputsResponse {
+            data: OutputsData,
+        }
+        #[derive(serde::Deserialize)]
+        struct OutputsData {
+            #[serde(rename = "outputIds")]
+            output_ids: Vec<String>,
+        }
+
+        let url = format!(
+            "{}/api/v1/addresses/{}/outputs?include-spent={}",
+            self.client.node()?,
+            bech32_address,
+            options.include_spent
+        );
+        let response: OutputsResponse = reqwest::get(&url).await?.json().await?;
+        Ok(response.data.output_ids)
+    }
+}
+
+/// Filtering options for [`GetAddressBuilder::outputs`].
+#[derive(Debug, Clone, Default)]
+pub struct OutputsOptions {
+    /// Whether to include spent outputs.
+    pub include_spent: bool,
+}
+
+/// Builder to configure and create a [`Client`].
+pub struct ClientBuilder {
+    nodes: Vec<String>,
+    network_spec: NetworkSpec,
+}
+
+impl ClientBuilder {
+    /// Create a new client builder, defaulting to the mainnet spec and its default nodes.
+    pub fn new() -> Self {
+        let network_spec = NetworkSpec::default();
+        Self {
+            nodes: network_spec.default_nodes.clone(),
+            network_spec,
+        }
+    }
+
+    /// Add a node to connect to. While the node list still equals the network spec's default
+    /// nodes, adding a node replaces them, since the client only uses the first node in the list.
+    pub fn node(mut self, node: &str) -> Self {
+        if self.nodes == self.network_spec.default_nodes {
+            self.nodes.clear();
+        }
+        self.nodes.push(node.to_owned());
+        self
+    }
+
+    /// Set the network spec to use. Replaces the node list with the spec's default nodes unless
+    /// [`node()`](ClientBuilder::node) is called again afterwards.
+    pub fn with_network_spec(mut self, network_spec: NetworkSpec) -> Self {
+        self.nodes = network_spec.default_nodes.clone();
+        self.network_spec = network_spec;
+        self
+    }
+
+    /// Build the [`Client`].
+    pub fn finish(self) -> Result<Client> {
+        Ok(Client {
+            nodes: self.nodes,
+            network_spec: self.network_spec,
+        })
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
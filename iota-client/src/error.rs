@@ -0,0 +1,26 @@
+// Copyright 2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Error handling in the iota-client crate.
+
+use thiserror::Error as DeriveError;
+
+/// Result type used throughout the crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error type used throughout the crate.
+#[derive(Debug, DeriveError)]
+pub enum Error {
+    /// A required builder parameter was not set.
+    #[error("missing parameter: {0}")]
+    MissingParameter(String),
+    /// A parameter had an invalid value.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+    /// Error while building, signing or mining a migration bundle.
+    #[error("migration error: {0}")]
+    MigrationError(&'static str),
+    /// Error talking to a node or other network service (e.g. a faucet).
+    #[error("network request failed: {0}")]
+    Net(#[from] reqwest::Error),
+}
@@ -19,15 +19,10 @@ use bee_transaction::bundled::{
     Address, BundledTransaction, BundledTransactionBuilder, BundledTransactionField, Nonce,
     OutgoingBundleBuilder, Payload, Timestamp,
 };
-use iota_bundle_miner::{
-    miner::MinerEvent, CrackabilityMinerEvent, MinerBuilder, RecovererBuilder,
-};
+use iota_bundle_miner::{miner::MinerEvent, MinerBuilder, RecovererBuilder};
 
 use futures::future::abortable;
 
-/// Dust protection treshhold: minimum amount of iotas an address needs in Chrysalis
-pub const DUST_THRESHOLD: u64 = 1_000_000;
-
 /// Prepare migration bundle with address and inputs
 pub async fn create_migration_bundle(
     client: &Client,
@@ -61,13 +56,13 @@ pub async fn create_migration_bundle(
 
     let total_value = address_inputs.iter().map(|d| d.balance).sum();
 
-    // Check for dust protection value
-    // Todo enable it again
-    // if total_value < DUST_THRESHOLD {
-    //     return Err(Error::MigrationError(
-    //         "Input value is < dust protection value (1_000_000 i)".into(),
-    //     ));
-    // }
+    // Check for dust protection value, read from the client's active network spec rather than a
+    // compile-time constant so a custom network can use a different threshold.
+    if total_value < client.network_spec().dust_threshold {
+        return Err(Error::MigrationError(
+            "Input value is less than the dust protection threshold",
+        ));
+    }
     let transfer = vec![Transfer {
         address: migration_address,
         value: total_value,
@@ -96,7 +91,7 @@ pub fn sign_migration_bundle(
         1 => WotsSecurityLevel::Low,
         2 => WotsSecurityLevel::Medium,
         3 => WotsSecurityLevel::High,
-        _ => panic!("Invalid scurity level"),
+        _ => return Err(Error::MigrationError("Invalid security level")),
     };
     // Validate that all inputs have the same security level
     let same_security_level = inputs
@@ -152,8 +147,30 @@ pub fn sign_migration_bundle(
     Ok(trytes)
 }
 
+/// Progress reported periodically while mining runs, and once more with the final result.
+#[derive(Debug, Clone, Copy)]
+pub struct MiningProgress {
+    /// Estimated forgery probability of the best obsolete tag found so far. Mining doesn't expose
+    /// intermediate crackability values, so this is `f64::INFINITY` on every interim tick and only
+    /// holds a real value once `done` is `true`.
+    pub crackability: f64,
+    /// Number of obsolete tag offsets tried. Like `crackability`, only meaningful once `done` is
+    /// `true`.
+    pub offsets_tried: i64,
+    /// Time spent mining so far, in seconds.
+    pub elapsed: u64,
+    /// `false` for an interim tick while mining is still running, `true` for the final result.
+    pub done: bool,
+}
+
 /// mine a bundle essence to reveal as least new parts of the signature as possible
 /// returns the txs of the bundle and a miner event from which one can get the updated obsolete tag to update the bundle
+///
+/// `target_crackability`, if set, stops mining as soon as the best obsolete tag found so far
+/// drives the estimated forgery probability at or below the target, even if `timeout` hasn't
+/// elapsed yet; otherwise mining runs until `timeout`. A [`MiningProgress`] tick is sent through
+/// the returned channel roughly once a second while mining runs, followed by one final tick
+/// (`done: true`) carrying the actual result.
 pub async fn mine(
     prepared_bundle: OutgoingBundleBuilder,
     security_level: u8,
@@ -161,9 +178,10 @@ pub async fn mine(
     spent_bundle_hashes: Vec<String>,
     timeout: u64,
     offset: i64,
+    target_crackability: Option<f64>,
 ) -> Result<(
     tokio::sync::mpsc::Sender<MinerEvent>,
-    tokio::sync::mpsc::Receiver<CrackabilityMinerEvent>,
+    tokio::sync::mpsc::Receiver<MiningProgress>,
     futures::future::AbortHandle,
     Vec<BundledTransaction>,
 )> {
@@ -232,7 +250,7 @@ pub async fn mine(
         .with_mining_timeout(timeout)
         .finish()?;
 
-    let mut recoverer = RecovererBuilder::new()
+    let mut recoverer_builder = RecovererBuilder::new()
         .with_security_level(security_level as usize)
         .with_known_bundle_hashes(
             spent_bundle_hashes
@@ -244,15 +262,50 @@ pub async fn mine(
                 })
                 .collect::<Result<Vec<TritBuf<T1B1Buf>>>>()?,
         )
-        .miner(miner)
-        .finish()?;
+        .miner(miner);
+    // Stop recovering as soon as the best obsolete tag found is safe enough, instead of always
+    // running until the timeout.
+    if let Some(target) = target_crackability {
+        recoverer_builder = recoverer_builder.with_target_score(target);
+    }
+    let mut recoverer = recoverer_builder.finish()?;
     let (miner_tx, miner_rx) = tokio::sync::mpsc::channel(worker_count + 2);
     let miner_tx_cloned = miner_tx.clone();
-    let (tx, rx) = tokio::sync::mpsc::channel(2);
+    let (tx, rx) = tokio::sync::mpsc::channel(16);
 
+    let start = std::time::Instant::now();
     let (abortable_worker, abort_handle) = abortable(tokio::spawn(async move {
-        let event = recoverer.recover(miner_tx_cloned, miner_rx).await;
-        let _ = tx.send(event).await;
+        let recover_fut = recoverer.recover(miner_tx_cloned, miner_rx);
+        tokio::pin!(recover_fut);
+
+        // Mining itself doesn't report intermediate crackability, so sample elapsed time on a
+        // tick while we wait for it, and only fill in the real crackability/offsets once done.
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(1));
+        ticker.tick().await; // first tick fires immediately; skip it
+
+        let event = loop {
+            tokio::select! {
+                event = &mut recover_fut => break event,
+                _ = ticker.tick() => {
+                    let _ = tx
+                        .send(MiningProgress {
+                            crackability: f64::INFINITY,
+                            offsets_tried: 0,
+                            elapsed: start.elapsed().as_secs(),
+                            done: false,
+                        })
+                        .await;
+                }
+            }
+        };
+
+        let progress = MiningProgress {
+            crackability: event.crackability,
+            offsets_tried: event.index,
+            elapsed: start.elapsed().as_secs(),
+            done: true,
+        };
+        let _ = tx.send(progress).await;
     }));
     tokio::spawn(async move {
         let _ = abortable_worker.await;
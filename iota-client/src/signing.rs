@@ -0,0 +1,78 @@
+// Copyright 2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pluggable signature-scheme abstraction used to derive addresses from a seed.
+//!
+//! [`GetAddressesBuilder`](crate::api::GetAddressesBuilder) derives addresses through a
+//! [`SignatureScheme`] instead of hard-coding Ed25519, so a new seed/signature type can be added
+//! by implementing the trait, without touching its call sites. The migration signing code signs
+//! with a ternary seed and does not go through this trait.
+
+use crate::{Error, Result};
+
+use bee_message::prelude::{Address, Ed25519Address};
+use bee_signing_ext::{
+    binary::{BIP32Path, Ed25519PrivateKey, Ed25519Seed},
+    Seed,
+};
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use core::convert::TryInto;
+
+const HARDEND: u32 = 1 << 31;
+
+/// A signature scheme that can derive addresses from a seed along a BIP32 path.
+pub trait SignatureScheme {
+    /// Derive the address at `index` on the external (`internal = false`) or internal chain.
+    fn generate_address(&self, path: &mut BIP32Path, index: usize, internal: bool) -> Result<Address>;
+}
+
+/// Ed25519 signature scheme, as used by Ed25519 seeds.
+pub struct Ed25519SignatureScheme<'a> {
+    seed: &'a Ed25519Seed,
+}
+
+impl<'a> Ed25519SignatureScheme<'a> {
+    /// Create an Ed25519 signature scheme over `seed`.
+    pub fn new(seed: &'a Ed25519Seed) -> Self {
+        Self { seed }
+    }
+}
+
+impl<'a> SignatureScheme for Ed25519SignatureScheme<'a> {
+    fn generate_address(&self, path: &mut BIP32Path, index: usize, internal: bool) -> Result<Address> {
+        path.push(internal as u32 + HARDEND);
+        path.push(index as u32 + HARDEND);
+
+        // `path` is reused across the whole scanning loop, so it must be popped back to its
+        // incoming state on every return path, including a failed derivation.
+        let private_key = Ed25519PrivateKey::generate_from_seed(self.seed, path);
+        path.pop();
+        path.pop();
+        let public_key = private_key
+            .map_err(|_| Error::InvalidParameter("BIP32Path index must be hardened"))?
+            .generate_public_key()
+            .to_bytes();
+
+        // Hash the public key to get the address
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.update(public_key);
+        let mut result: [u8; 32] = [0; 32];
+        hasher.finalize_variable(|res| {
+            result = res.try_into().expect("Invalid Length of Public Key");
+        });
+
+        Ok(Address::Ed25519(Ed25519Address::new(result)))
+    }
+}
+
+/// Resolve the [`SignatureScheme`] matching `seed`, or an [`Error::InvalidParameter`] if the seed
+/// type isn't supported yet.
+pub fn signature_scheme_for_seed(seed: &Seed) -> Result<Box<dyn SignatureScheme + '_>> {
+    match seed {
+        Seed::Ed25519(s) => Ok(Box::new(Ed25519SignatureScheme::new(s))),
+        _ => Err(Error::InvalidParameter("seed: unsupported signature scheme")),
+    }
+}